@@ -115,6 +115,11 @@ pub enum MetadataKind {
     ///
     /// If the extended attribute's name or type is known, it should be provided in the
     /// `Info` event attribute.
+    ///
+    /// Of the notify backends, only FSEvents (macOS) reports this kind. inotify and kqueue report
+    /// extended attribute changes as `Metadata(Any)`. The Windows backend does not subscribe to
+    /// extended attribute changes and the poll watcher does not detect them, so neither reports
+    /// them at all.
     Extended,
 
     /// An event which specific kind is known but cannot be represented otherwise.
@@ -277,6 +282,41 @@ impl EventKind {
     pub fn is_other(&self) -> bool {
         matches!(self, EventKind::Other)
     }
+
+    /// Indicates whether an event may be a change of a file's data.
+    ///
+    /// This includes `Modify(Any)`, which backends emit when they can't tell what kind of
+    /// modification happened (e.g. on Windows).
+    ///
+    /// ```
+    /// use notify_types::event::{DataChange, EventKind, MetadataKind, ModifyKind};
+    ///
+    /// assert!(EventKind::Modify(ModifyKind::Data(DataChange::Content)).is_content_modification());
+    /// assert!(EventKind::Modify(ModifyKind::Data(DataChange::Any)).is_content_modification());
+    /// assert!(EventKind::Modify(ModifyKind::Any).is_content_modification());
+    /// assert!(!EventKind::Modify(ModifyKind::Metadata(MetadataKind::Any)).is_content_modification());
+    /// ```
+    #[must_use]
+    pub fn is_content_modification(&self) -> bool {
+        matches!(
+            self,
+            EventKind::Modify(ModifyKind::Data(_)) | EventKind::Modify(ModifyKind::Any)
+        )
+    }
+
+    /// Indicates whether an event is a rename, in any [`RenameMode`].
+    ///
+    /// ```
+    /// use notify_types::event::{EventKind, ModifyKind, RenameMode};
+    ///
+    /// assert!(EventKind::Modify(ModifyKind::Name(RenameMode::Both)).is_rename());
+    /// assert!(EventKind::Modify(ModifyKind::Name(RenameMode::Any)).is_rename());
+    /// assert!(!EventKind::Modify(ModifyKind::Any).is_rename());
+    /// ```
+    #[must_use]
+    pub fn is_rename(&self) -> bool {
+        matches!(self, EventKind::Modify(ModifyKind::Name(_)))
+    }
 }
 
 bitflags! {
@@ -468,6 +508,9 @@ pub struct Event {
     ///
     /// The order of the paths is likely to be significant! For example, renames where both ends of
     /// the name change are known will have the "source" path first, and the "target" path last.
+    ///
+    /// With the `serde` feature, paths are serialized as plain strings. Serialization is strict:
+    /// a path that is not valid UTF-8 makes serialization fail instead of being converted lossily.
     pub paths: Vec<PathBuf>,
 
     // "What should be in the struct" and "what can go in the attrs" is an interesting question.
@@ -513,9 +556,9 @@ pub struct EventAttributes {
 struct EventAttributesInner {
     /// Tracking ID for events that are related.
     ///
-    /// For events generated by backends with the `TrackRelated` capability. Those backends _may_
-    /// emit events that are related to each other, and tag those with an identical "tracking id"
-    /// or "cookie". The value is normalised to `usize`.
+    /// Backends that can tell that several events are related to each other _may_ tag those with
+    /// an identical "tracking id" or "cookie". The value is normalised to `usize`. See
+    /// [`Event::tracker`] for its guarantees.
     #[cfg_attr(
         feature = "serde",
         serde(default, skip_serializing_if = "Option::is_none")
@@ -548,9 +591,8 @@ struct EventAttributesInner {
 
     /// The source of the event.
     ///
-    /// In most cases this should be a short string, identifying the backend unambiguously. In some
-    /// cases this may be dynamically generated, but should contain a prefix to make it unambiguous
-    /// between backends.
+    /// A short string identifying where the event came from, set by code that merges events from
+    /// several watchers. See [`EventAttributes::set_source`].
     #[cfg_attr(
         feature = "serde",
         serde(default, skip_serializing_if = "Option::is_none")
@@ -655,6 +697,16 @@ impl EventAttributes {
         self.inner_mut().info = Some(info.to_string());
     }
 
+    /// Sets the source of the event.
+    ///
+    /// The built-in backends don't set it. It is meant for code that merges events from several
+    /// watchers into one stream and wants them to stay attributable. The value should be a short
+    /// string that tells the watchers apart unambiguously, e.g. the watcher's name or its watch
+    /// root.
+    pub fn set_source(&mut self, source: &str) {
+        self.inner_mut().source = Some(source.to_string());
+    }
+
     /// Sets the process id onto the event.
     pub fn set_process_id(&mut self, process_id: u32) {
         self.inner_mut().process_id = Some(process_id)
@@ -696,6 +748,16 @@ impl Event {
         matches!(self.flag(), Some(Flag::Rescan))
     }
     /// Retrieves the tracker ID for an event directly, if present.
+    ///
+    /// The tracker correlates the halves of a rename: a `Modify(Name(From))` and a
+    /// `Modify(Name(To))` event with the same tracker describe the same move, and the
+    /// `Modify(Name(Both))` event emitted for the pair carries it as well. Currently only the
+    /// inotify backend sets it, using the kernel's rename cookie.
+    ///
+    /// A tracker is only meaningful between events of the same watcher that are close in time.
+    /// It is not unique across watchers, is eventually reused, and has no ordering. A `From`
+    /// whose tracker is never matched by a `To` is a move out of the watched tree, and vice
+    /// versa.
     #[must_use]
     pub fn tracker(&self) -> Option<usize> {
         self.attrs.tracker()
@@ -774,6 +836,13 @@ impl Event {
         self
     }
 
+    /// Sets [`EventAttributes::source`], see [`EventAttributes::set_source`].
+    #[must_use]
+    pub fn set_source(mut self, source: &str) -> Self {
+        self.attrs.set_source(source);
+        self
+    }
+
     /// Sets the process id onto the event.
     #[must_use]
     pub fn set_process_id(mut self, process_id: u32) -> Self {
@@ -1061,6 +1130,29 @@ mod tests {
         let event = Event::new(event_kind);
         let json = serde_json::to_string(&event).unwrap();
         assert_snapshot!(name, json);
+
+        let deserialized: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, event);
+    }
+
+    #[test]
+    fn event_paths_roundtrip() {
+        let event = Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+            .add_path(PathBuf::from("/from"))
+            .add_path(PathBuf::from("/to"));
+        let json = serde_json::to_string(&event).unwrap();
+        let deserialized: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, event);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn serialize_non_utf8_path_fails() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let event = Event::new(EventKind::Any).add_path(PathBuf::from(OsStr::from_bytes(b"\xff")));
+        assert!(serde_json::to_string(&event).is_err());
     }
 
     #[test]
@@ -1073,4 +1165,21 @@ mod tests {
         let json = serde_json::to_string(&event).unwrap();
         assert_snapshot!(json);
     }
+
+    #[test]
+    fn event_source_tag() {
+        let path = PathBuf::from("/a");
+        let untagged = Event::new(EventKind::Any).add_path(path.clone());
+        let first = untagged.clone().set_source("first");
+        let second = untagged.clone().set_source("second");
+
+        assert_eq!(untagged.source(), None);
+        assert_eq!(first.source(), Some("first"));
+        assert_eq!(second.source(), Some("second"));
+        assert_ne!(first, second);
+        assert_ne!(first, untagged);
+
+        let json = serde_json::to_string(&first).unwrap();
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), first);
+    }
 }
@@ -76,6 +76,9 @@ pub struct Config {
 
     /// See [Config::with_fsevent_latency]
     fsevent_latency: Duration,
+
+    /// See [Config::with_windows_buffer_size]
+    windows_buffer_size: u32,
 }
 
 impl Config {
@@ -160,6 +163,15 @@ impl Config {
     /// performance. On other backends (kqueue, Windows, FSEvents, PollWatcher),
     /// filtering is applied in userspace.
     ///
+    /// inotify still registers some flags beyond the selected kinds: `IN_DELETE_SELF` and
+    /// `IN_MOVE_SELF` on the watched path itself, `IN_CREATE` and `IN_MOVED_TO` on recursive
+    /// watches to pick up new subdirectories, and `IN_MOVED_TO` or `IN_MOVED_FROM` along with
+    /// creates or removes. Events of unselected kinds produced by these flags, such as the
+    /// `Modify(Name(..))` events of moves, are dropped in userspace. Errors,
+    /// [`EventKind::Any`](crate::EventKind::Any) and
+    /// [`EventKind::Other`](crate::EventKind::Other) (e.g. a rescan notification) are always
+    /// delivered regardless of the mask.
+    ///
     /// The default is [`EventKindMask::ALL`], which includes all events.
     /// Use [`EventKindMask::CORE`] to exclude access events.
     ///
@@ -235,6 +247,34 @@ impl Config {
     pub fn fsevent_latency(&self) -> Duration {
         self.fsevent_latency
     }
+
+    /// For the [`ReadDirectoryChangesWatcher`](crate::ReadDirectoryChangesWatcher) backend.
+    ///
+    /// Size in bytes of the buffer each watch passes to `ReadDirectoryChangesW`. When more
+    /// changes happen between two reads than fit into the buffer, the system discards them
+    /// and the watcher emits an event flagged with [`Flag::Rescan`](crate::event::Flag::Rescan)
+    /// instead, carrying the path of the affected watch. A larger buffer makes that less likely
+    /// at the cost of memory per watch.
+    ///
+    /// The default is 16384 bytes. The size must be at least 524 bytes, otherwise the watcher
+    /// fails to be created with [`ErrorKind::InvalidConfig`](crate::ErrorKind::InvalidConfig).
+    /// That is enough for a single change record with a file name of maximum length, so a
+    /// non-recursive watch always makes progress. Recursive watches report paths relative to the
+    /// watch root, which can be longer, so changes deep in the tree may overflow a buffer that
+    /// small every time. Watches on network shares fail when the buffer is larger than 64 KB.
+    ///
+    /// This can't be changed during runtime.
+    #[must_use]
+    pub fn with_windows_buffer_size(mut self, size: u32) -> Self {
+        self.windows_buffer_size = size;
+        self
+    }
+
+    /// Returns current setting
+    #[must_use]
+    pub fn windows_buffer_size(&self) -> u32 {
+        self.windows_buffer_size
+    }
 }
 
 impl Default for Config {
@@ -246,6 +286,7 @@ impl Default for Config {
             event_kinds: EventKindMask::ALL,
             windows_path_separator_style: WindowsPathSeparatorStyle::Auto,
             fsevent_latency: Duration::ZERO,
+            windows_buffer_size: 16384,
         }
     }
 }
@@ -400,4 +441,11 @@ mod tests {
         let config = Config::default().with_fsevent_latency(latency);
         assert_eq!(config.fsevent_latency(), latency);
     }
+
+    #[test]
+    fn config_with_windows_buffer_size() {
+        assert_eq!(Config::default().windows_buffer_size(), 16384);
+        let config = Config::default().with_windows_buffer_size(65536);
+        assert_eq!(config.windows_buffer_size(), 65536);
+    }
 }
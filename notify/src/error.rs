@@ -29,10 +29,17 @@ pub enum ErrorKind {
     WatchNotFound,
 
     /// An invalid value was passed as runtime configuration.
-    InvalidConfig(Config),
+    InvalidConfig(Box<Config>),
 
     /// Can't watch (more) files, limit on the total number of inotify watches reached
     MaxFilesWatch,
+
+    /// A backend-specific error.
+    ///
+    /// Lets backends outside this crate pass their own error types through instead of
+    /// flattening them into [`ErrorKind::Generic`]. Match on the other variants first and only
+    /// use [`Error::downcast_ref`] when you know which backend produced the error.
+    Backend(Box<dyn StdError + Send + Sync>),
 }
 
 /// Notify error type.
@@ -97,6 +104,31 @@ impl Error {
         }
     }
 
+    /// Creates a new backend-specific Error wrapping the given error.
+    #[must_use]
+    pub fn backend<E>(err: E) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        Self::new(ErrorKind::Backend(Box::new(err)))
+    }
+
+    /// Returns the wrapped error if it is of type `E`.
+    ///
+    /// The wrapped error is the [`io::Error`] of [`ErrorKind::Io`] or the backend-specific error
+    /// of [`ErrorKind::Backend`]. Returns `None` for the other kinds.
+    #[must_use]
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: StdError + 'static,
+    {
+        match self.kind {
+            ErrorKind::Io(ref err) => (err as &(dyn StdError + 'static)).downcast_ref(),
+            ErrorKind::Backend(ref err) => err.downcast_ref(),
+            _ => None,
+        }
+    }
+
     /// Creates a new "path not found" error.
     #[must_use]
     pub fn path_not_found() -> Self {
@@ -112,7 +144,7 @@ impl Error {
     /// Creates a new "invalid config" error from the given `Config`.
     #[must_use]
     pub fn invalid_config(config: &Config) -> Self {
-        Self::new(ErrorKind::InvalidConfig(*config))
+        Self::new(ErrorKind::InvalidConfig(Box::new(*config)))
     }
 }
 
@@ -125,6 +157,7 @@ impl fmt::Display for Error {
             ErrorKind::Generic(ref err) => err.clone(),
             ErrorKind::Io(ref err) => err.to_string(),
             ErrorKind::MaxFilesWatch => "OS file watch limit reached.".into(),
+            ErrorKind::Backend(ref err) => err.to_string(),
         };
 
         if self.paths.is_empty() {
@@ -136,9 +169,10 @@ impl fmt::Display for Error {
 }
 
 impl StdError for Error {
-    fn cause(&self) -> Option<&dyn StdError> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self.kind {
             ErrorKind::Io(ref cause) => Some(cause),
+            ErrorKind::Backend(ref cause) => Some(cause.as_ref()),
             _ => None,
         }
     }
@@ -150,6 +184,34 @@ impl From<io::Error> for Error {
     }
 }
 
+/// Converts the error into an [`io::Error`], for use in APIs returning [`io::Result`].
+///
+/// | [`ErrorKind`] | [`io::ErrorKind`] |
+/// | --- | --- |
+/// | [`Io`](ErrorKind::Io) | the wrapped error, returned as is |
+/// | [`PathNotFound`](ErrorKind::PathNotFound) | [`NotFound`](io::ErrorKind::NotFound) |
+/// | [`WatchNotFound`](ErrorKind::WatchNotFound) | [`NotFound`](io::ErrorKind::NotFound) |
+/// | [`InvalidConfig`](ErrorKind::InvalidConfig) | [`InvalidInput`](io::ErrorKind::InvalidInput) |
+/// | [`MaxFilesWatch`](ErrorKind::MaxFilesWatch) | [`QuotaExceeded`](io::ErrorKind::QuotaExceeded) |
+/// | [`Generic`](ErrorKind::Generic) | [`Other`](io::ErrorKind::Other) |
+/// | [`Backend`](ErrorKind::Backend) | [`Other`](io::ErrorKind::Other) |
+///
+/// Except for `Io`, the original `Error` (including its paths) is kept as the inner error of the
+/// returned `io::Error` and can be retrieved with [`io::Error::into_inner`]. For `Io`, the paths
+/// are dropped so that [`io::Error::raw_os_error`] keeps working.
+impl From<Error> for io::Error {
+    fn from(err: Error) -> Self {
+        let kind = match err.kind {
+            ErrorKind::Io(err) => return err,
+            ErrorKind::PathNotFound | ErrorKind::WatchNotFound => io::ErrorKind::NotFound,
+            ErrorKind::InvalidConfig(_) => io::ErrorKind::InvalidInput,
+            ErrorKind::MaxFilesWatch => io::ErrorKind::QuotaExceeded,
+            ErrorKind::Generic(_) | ErrorKind::Backend(_) => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, err)
+    }
+}
+
 impl<T> From<std::sync::mpsc::SendError<T>> for Error {
     fn from(err: std::sync::mpsc::SendError<T>) -> Self {
         Error::generic(&format!("internal channel disconnect: {err:?}"))
@@ -236,6 +298,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn io_error_is_exposed_as_source() {
+        fn propagate() -> StdResult<(), Box<dyn StdError + Send + Sync>> {
+            Err(Error::io(io::Error::other("Some error")).add_path(PathBuf::from("/a")))?;
+            Ok(())
+        }
+
+        let err = propagate().unwrap_err();
+        assert_eq!("Some error about [\"/a\"]", err.to_string());
+
+        let source = err.source().expect("io error as source");
+        assert_eq!("Some error", source.to_string());
+        assert!(source.is::<io::Error>());
+
+        assert!(Error::path_not_found().source().is_none());
+    }
+
+    #[test]
+    fn backend_error_downcast() {
+        #[derive(Debug)]
+        struct NativeError(i32);
+
+        impl fmt::Display for NativeError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "native error {}", self.0)
+            }
+        }
+
+        impl StdError for NativeError {}
+
+        let err = Error::backend(NativeError(42));
+
+        assert_eq!("native error 42", err.to_string());
+        assert_eq!(42, err.downcast_ref::<NativeError>().unwrap().0);
+        assert!(err.downcast_ref::<io::Error>().is_none());
+        assert!(err.source().unwrap().is::<NativeError>());
+
+        assert!(Error::generic("Some error")
+            .downcast_ref::<NativeError>()
+            .is_none());
+
+        let err = Error::io(io::Error::other("io"));
+        assert_eq!("io", err.downcast_ref::<io::Error>().unwrap().to_string());
+        assert!(err.downcast_ref::<NativeError>().is_none());
+    }
+
+    #[test]
+    fn convert_into_io_error() {
+        let err = io::Error::from(Error::io(io::Error::from_raw_os_error(2)));
+        assert_eq!(Some(2), err.raw_os_error());
+
+        let err = io::Error::from(Error::path_not_found().add_path(PathBuf::from("/a")));
+        assert_eq!(io::ErrorKind::NotFound, err.kind());
+        let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+        assert!(matches!(inner.kind, ErrorKind::PathNotFound));
+        assert_eq!(vec![PathBuf::from("/a")], inner.paths);
+
+        let cases = [
+            (Error::watch_not_found(), io::ErrorKind::NotFound),
+            (
+                Error::invalid_config(&Config::default()),
+                io::ErrorKind::InvalidInput,
+            ),
+            (
+                Error::new(ErrorKind::MaxFilesWatch),
+                io::ErrorKind::QuotaExceeded,
+            ),
+            (Error::generic("Some error"), io::ErrorKind::Other),
+        ];
+        for (err, kind) in cases {
+            assert_eq!(kind, io::Error::from(err).kind());
+        }
+    }
+
     #[test]
     fn display_update_paths() {
         let actual = UpdatePathsError {
@@ -992,6 +992,108 @@ mod tests {
         panic!("did not receive expected child event path");
     }
 
+    #[test]
+    fn repeated_watch_unwatch_keeps_other_watches_and_stops_removed_ones(
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        const ITERATIONS: usize = 20;
+        const QUIET_PERIOD: Duration = Duration::from_millis(300);
+
+        fn assert_not_unwatched(event: &Event, unwatched: &[(PathBuf, Option<PathBuf>)]) {
+            for path in &event.paths {
+                for (file, canonical) in unwatched {
+                    assert!(
+                        !matches_path(path, file, canonical.as_ref()),
+                        "Event of {file:?} should not be received"
+                    );
+                }
+            }
+        }
+
+        let dir = tempdir()?;
+        let dir_a = dir.path().join("a");
+        let dir_b = dir.path().join("b");
+        fs::create_dir(&dir_a)?;
+        fs::create_dir(&dir_b)?;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut watcher = RecommendedWatcher::new(tx, Config::default())?;
+        watch_with_retry(&mut watcher, &dir_b, RecursiveMode::Recursive)?;
+
+        // files written to `a` while it isn't watched; none of them may ever be reported
+        let mut unwatched = Vec::new();
+
+        // repeatedly add and remove a watch; while `a` is watched, both directories must deliver
+        for i in 0..ITERATIONS {
+            watch_with_retry(&mut watcher, &dir_a, RecursiveMode::Recursive)?;
+            let a_file = dir_a.join(format!("watched_{i}"));
+            let b_file = dir_b.join(format!("watched_{i}"));
+            fs::write(&a_file, b"Lorem ipsum")?;
+            fs::write(&b_file, b"Lorem ipsum")?;
+            let a_file_canonical = a_file.canonicalize().ok();
+            let b_file_canonical = b_file.canonicalize().ok();
+
+            let (mut seen_a, mut seen_b) = (false, false);
+            for event in iter_with_timeout(&rx) {
+                assert_not_unwatched(&event, &unwatched);
+                for path in &event.paths {
+                    seen_a |= matches_path(path, &a_file, a_file_canonical.as_ref());
+                    seen_b |= matches_path(path, &b_file, b_file_canonical.as_ref());
+                }
+                if seen_a && seen_b {
+                    break;
+                }
+            }
+            assert!(seen_a, "Did not receive the event of {a_file:?}");
+            assert!(seen_b, "Did not receive the event of {b_file:?}");
+
+            update_paths_unwatch_with_retry(&mut watcher, &dir_a)?;
+
+            let a_file = dir_a.join(format!("unwatched_{i}"));
+            fs::write(&a_file, b"Lorem ipsum")?;
+            let a_file_canonical = a_file.canonicalize().ok();
+            unwatched.push((a_file, a_file_canonical));
+        }
+
+        let watched = canonical_watch_set(watcher.watched_paths()?);
+        assert!(
+            !watched
+                .iter()
+                .any(|(path, _mode)| path == &canonical_or_path(&dir_a)),
+            "removed watch is still registered: {watched:?}"
+        );
+        assert!(watched.contains(&(canonical_or_path(&dir_b), RecursiveMode::Recursive)));
+
+        // the remaining watch must still deliver, the removed one must not
+        let a_file = dir_a.join("after");
+        let b_file = dir_b.join("after");
+        fs::write(&a_file, b"Lorem ipsum")?;
+        fs::write(&b_file, b"Lorem ipsum")?;
+        let a_file_canonical = a_file.canonicalize().ok();
+        let b_file_canonical = b_file.canonicalize().ok();
+        unwatched.push((a_file, a_file_canonical));
+
+        let mut seen_b = false;
+        for event in iter_with_timeout(&rx) {
+            assert_not_unwatched(&event, &unwatched);
+            if event
+                .paths
+                .iter()
+                .any(|path| matches_path(path, &b_file, b_file_canonical.as_ref()))
+            {
+                seen_b = true;
+                break;
+            }
+        }
+        assert!(seen_b, "Did not receive the event of {b_file:?}");
+
+        // late events of the removed watch may arrive after those of the remaining one
+        while let Ok(event) = rx.recv_timeout(QUIET_PERIOD) {
+            assert_not_unwatched(&event.expect("received an error"), &unwatched);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn update_paths_in_a_loop_with_errors() -> StdResult<(), Box<dyn std::error::Error>> {
         let dir = tempdir()?;
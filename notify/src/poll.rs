@@ -390,6 +390,9 @@ mod data {
         /// File updated time.
         mtime: i64,
 
+        /// File size in bytes, only available for regular files.
+        len: Option<u64>,
+
         /// Content's hash value, only available if user request compare file
         /// contents and read successful.
         hash: Option<u64>,
@@ -405,6 +408,7 @@ mod data {
 
             PathData {
                 mtime: metadata.modified().map_or(0, system_time_to_seconds),
+                len: metadata.is_file().then_some(metadata.len()),
                 hash: if data_builder.compare_contents && metadata.is_file() {
                     content_hash(meta_path.path()).ok()
                 } else {
@@ -418,7 +422,9 @@ mod data {
         fn compare_to_kind(old: Option<&PathData>, new: Option<&PathData>) -> Option<EventKind> {
             match (old, new) {
                 (Some(old), Some(new)) => {
-                    if new.mtime > old.mtime {
+                    if new.len != old.len {
+                        Some(EventKind::Modify(ModifyKind::Data(DataChange::Size)))
+                    } else if new.mtime > old.mtime {
                         Some(EventKind::Modify(ModifyKind::Metadata(
                             MetadataKind::WriteTime,
                         )))
@@ -537,8 +543,12 @@ mod data {
 
 /// Polling based `Watcher` implementation.
 ///
-/// By default scans through all files and checks for changed entries based on their change date.
-/// Can also be changed to perform file content change checks.
+/// By default scans through all files and checks for changed entries based on their size and
+/// change date. Can also be changed to perform file content change checks.
+///
+/// A file whose size changed is reported as `Modify(Data(Size))`, one whose change date advanced
+/// as `Modify(Metadata(WriteTime))`, and, with content checks enabled, one whose content changed
+/// otherwise as `Modify(Data(Any))`. Only the first matching kind is reported per scan.
 ///
 /// See [Config] for more details.
 #[derive(Debug)]
@@ -912,6 +922,27 @@ mod tests {
         watcher.watch_recursively(&tmpdir);
         std::fs::write(&path, b"123").expect("Unable to write");
 
+        assert!(
+            rx.sleep_until(|| std::fs::read_to_string(&path).is_ok_and(|content| content == "123")),
+            "the file wasn't modified"
+        );
+        rx.wait_ordered_exact([expected(&path).modify_data_size()]);
+    }
+
+    #[test]
+    fn modify_file_same_size() {
+        let tmpdir = testdir();
+        let (mut watcher, mut rx) = watcher();
+        let path = tmpdir.path().join("entry");
+        std::fs::write(&path, b"abc").expect("Unable to write");
+
+        rx.sleep_until_parent_contains(&path);
+        rx.sleep_until_exists(&path);
+        rx.sleep_until_walkdir_returns_set(tmpdir.path(), [&path]);
+
+        watcher.watch_recursively(&tmpdir);
+        std::fs::write(&path, b"123").expect("Unable to write");
+
         assert!(
             rx.sleep_until(|| std::fs::read_to_string(&path).is_ok_and(|content| content == "123")),
             "the file wasn't modified"
@@ -919,6 +950,33 @@ mod tests {
         rx.wait_ordered_exact([expected(&path).modify_data_any()]);
     }
 
+    #[test]
+    fn append_file() {
+        use std::io::Write;
+
+        let tmpdir = testdir();
+        let (mut watcher, mut rx) = watcher();
+        let path = tmpdir.path().join("entry");
+        std::fs::write(&path, b"123").expect("Unable to write");
+
+        rx.sleep_until_parent_contains(&path);
+        rx.sleep_until_exists(&path);
+        rx.sleep_until_walkdir_returns_set(tmpdir.path(), [&path]);
+
+        watcher.watch_recursively(&tmpdir);
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(b"456"))
+            .expect("Unable to append");
+
+        assert!(
+            rx.sleep_until(|| std::fs::metadata(&path).is_ok_and(|metadata| metadata.len() == 6)),
+            "the file wasn't appended to"
+        );
+        rx.wait_ordered_exact([expected(&path).modify_data_size()]);
+    }
+
     #[test]
     fn remove_file() {
         let tmpdir = testdir();
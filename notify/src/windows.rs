@@ -12,6 +12,7 @@ use crate::{Error, EventHandler, RecursiveMode, Result, Watcher, WindowsPathSepa
 use std::alloc;
 use std::collections::HashMap;
 use std::ffi::OsString;
+use std::mem;
 use std::os::raw::c_void;
 use std::os::windows::ffi::{OsStrExt, OsStringExt};
 use std::path::{Path, PathBuf};
@@ -20,8 +21,8 @@ use std::slice;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use windows_sys::Win32::Foundation::{
-    CloseHandle, ERROR_ACCESS_DENIED, ERROR_OPERATION_ABORTED, ERROR_SUCCESS, HANDLE,
-    INVALID_HANDLE_VALUE, WAIT_OBJECT_0,
+    CloseHandle, ERROR_ACCESS_DENIED, ERROR_NOTIFY_ENUM_DIR, ERROR_OPERATION_ABORTED,
+    ERROR_SUCCESS, HANDLE, INVALID_HANDLE_VALUE, WAIT_OBJECT_0,
 };
 use windows_sys::Win32::Storage::FileSystem::{
     CreateFileW, ReadDirectoryChangesW, FILE_ACTION_ADDED, FILE_ACTION_MODIFIED,
@@ -37,7 +38,12 @@ use windows_sys::Win32::System::Threading::{
 };
 use windows_sys::Win32::System::IO::{CancelIo, OVERLAPPED};
 
-const BUF_SIZE: u32 = 16384;
+/// Smallest accepted buffer size: enough for one `FILE_NOTIFY_INFORMATION` record carrying a
+/// file name of the maximum length of 255 UTF-16 units. Recursive watches report names relative
+/// to the watch root, which can be longer, so this only guarantees progress for non-recursive
+/// watches.
+const MIN_BUF_SIZE: u32 =
+    (mem::offset_of!(FILE_NOTIFY_INFORMATION, FileName) + 255 * 2).next_multiple_of(4) as u32;
 
 #[derive(Clone, Copy)]
 enum SeparatorStyle {
@@ -133,10 +139,27 @@ struct ReadData {
     separator_style: SeparatorStyle,
 }
 
+impl ReadData {
+    /// Resolves the `FileName` of a `FILE_NOTIFY_INFORMATION` record, returning the path on disk
+    /// and the path to report.
+    ///
+    /// `FileName` is raw UTF-16 and may contain unpaired surrogates. `OsString::from_wide` stores
+    /// it as WTF-8, so the conversion is lossless and `encode_wide` gives back the exact name the
+    /// OS reported; never go through `String` here.
+    fn event_paths(&self, file_name: &[u16]) -> (PathBuf, PathBuf) {
+        let relative_path = PathBuf::from(OsString::from_wide(trim_leading_separators(file_name)));
+        let absolute_path = self.dir.join(&relative_path);
+        let path =
+            normalize_path_separators(self.reported_dir.join(relative_path), self.separator_style);
+        (absolute_path, path)
+    }
+}
+
 struct ReadDirectoryRequest {
     event_handler: Arc<Mutex<dyn EventHandler>>,
     event_kinds: EventKindMask,
-    buffer: [u8; BUF_SIZE as usize],
+    // `Vec<u32>` keeps the buffer DWORD-aligned, as `ReadDirectoryChangesW` requires
+    buffer: Vec<u32>,
     handle: HANDLE,
     data: ReadData,
     action_tx: Sender<Action>,
@@ -180,6 +203,7 @@ struct ReadDirectoryChangesServer {
     rx: Receiver<Action>,
     event_handler: Arc<Mutex<dyn EventHandler>>,
     event_kinds: EventKindMask,
+    buffer_size: u32,
     meta_tx: Sender<MetaEvent>,
     cmd_tx: Sender<Result<PathBuf>>,
     watches: HashMap<PathBuf, WatchState>,
@@ -190,6 +214,7 @@ impl ReadDirectoryChangesServer {
     fn start(
         event_handler: Arc<Mutex<dyn EventHandler>>,
         event_kinds: EventKindMask,
+        buffer_size: u32,
         meta_tx: Sender<MetaEvent>,
         cmd_tx: Sender<Result<PathBuf>>,
         wakeup_sem: HANDLE,
@@ -208,6 +233,7 @@ impl ReadDirectoryChangesServer {
                         rx: action_rx,
                         event_handler,
                         event_kinds,
+                        buffer_size,
                         meta_tx,
                         cmd_tx,
                         watches: HashMap::new(),
@@ -380,6 +406,7 @@ impl ReadDirectoryChangesServer {
             &rd,
             self.event_handler.clone(),
             self.event_kinds,
+            self.buffer_size,
             handle,
             self.tx.clone(),
         ) {
@@ -427,6 +454,7 @@ fn start_read(
     rd: &ReadData,
     event_handler: Arc<Mutex<dyn EventHandler>>,
     event_kinds: EventKindMask,
+    buffer_size: u32,
     handle: HANDLE,
     action_tx: Sender<Action>,
 ) -> Result<()> {
@@ -434,7 +462,7 @@ fn start_read(
         event_handler,
         event_kinds,
         handle,
-        buffer: [0u8; BUF_SIZE as usize],
+        buffer: vec![0u32; (buffer_size as usize).div_ceil(mem::size_of::<u32>())],
         data: rd.clone(),
         action_tx,
     });
@@ -466,7 +494,7 @@ fn start_read(
         let ret = ReadDirectoryChangesW(
             handle,
             request.buffer.as_mut_ptr() as *mut c_void,
-            BUF_SIZE,
+            mem::size_of_val(request.buffer.as_slice()) as u32,
             monitor_subdir,
             flags,
             &mut 0u32 as *mut u32, // not used for async reqs
@@ -497,7 +525,7 @@ fn start_read(
 
 unsafe extern "system" fn handle_event(
     error_code: u32,
-    _bytes_written: u32,
+    bytes_written: u32,
     overlapped: *mut OVERLAPPED,
 ) {
     let overlapped: Box<OVERLAPPED> = Box::from_raw(overlapped);
@@ -541,8 +569,8 @@ unsafe extern "system" fn handle_event(
                 return;
             }
         }
-        ERROR_SUCCESS => {
-            // Success, continue to handle the event
+        ERROR_SUCCESS | ERROR_NOTIFY_ENUM_DIR => {
+            // Success, continue to handle the event; an overflow is reported below
         }
         _ => {
             // Some unidentified error occurred, log and unwatch the directory, then return.
@@ -562,15 +590,40 @@ unsafe extern "system" fn handle_event(
         &request.data,
         request.event_handler.clone(),
         request.event_kinds,
+        mem::size_of_val(request.buffer.as_slice()) as u32,
         request.handle,
         request.action_tx.clone(),
     )
     .err();
 
+    // The changes didn't fit into the buffer and were discarded by the system. This completes
+    // with ERROR_NOTIFY_ENUM_DIR or, more commonly, with success and nothing written.
+    if error_code == ERROR_NOTIFY_ENUM_DIR || (error_code == ERROR_SUCCESS && bytes_written == 0) {
+        log::warn!(
+            "ReadDirectoryChangesW buffer overflow for directory {}",
+            request.data.dir.display()
+        );
+        let path = request.data.file.clone().unwrap_or_else(|| {
+            normalize_path_separators(
+                request.data.reported_dir.clone(),
+                request.data.separator_style,
+            )
+        });
+        let ev = Event::new(EventKind::Other)
+            .set_flag(Flag::Rescan)
+            .add_path(path);
+        emit_event(&request.event_handler, Ok(ev));
+        if let Some(err) = rearm_error {
+            emit_event(&request.event_handler, Err(err));
+            request.unwatch();
+        }
+        return;
+    }
+
     // The FILE_NOTIFY_INFORMATION struct has a variable length due to the variable length
     // string as its last member. Each struct contains an offset for getting the next entry in
     // the buffer.
-    let mut cur_offset: *const u8 = request.buffer.as_ptr();
+    let mut cur_offset: *const u8 = request.buffer.as_ptr() as *const u8;
     // In Wine, FILE_NOTIFY_INFORMATION structs are packed placed in the buffer;
     // they are aligned to 16bit (WCHAR) boundary instead of 32bit required by FILE_NOTIFY_INFORMATION.
     // Hence, we need to use `read_unaligned` here to avoid UB.
@@ -584,13 +637,7 @@ unsafe extern "system" fn handle_event(
             len,
         );
         // prepend root to get a full path
-        let relative_path =
-            PathBuf::from(OsString::from_wide(trim_leading_separators(encoded_path)));
-        let absolute_path = request.data.dir.join(&relative_path);
-        let path = normalize_path_separators(
-            request.data.reported_dir.join(relative_path),
-            request.data.separator_style,
-        );
+        let (absolute_path, path) = request.data.event_paths(encoded_path);
 
         // if we are watching a single file, ignore the event unless the path is exactly
         // the watched file
@@ -698,6 +745,7 @@ impl ReadDirectoryChangesWatcher {
             event_handler,
             event_kinds,
             WindowsPathSeparatorStyle::Auto,
+            Config::default().windows_buffer_size(),
             meta_tx,
         )
     }
@@ -706,6 +754,7 @@ impl ReadDirectoryChangesWatcher {
         event_handler: Arc<Mutex<dyn EventHandler>>,
         event_kinds: EventKindMask,
         windows_path_separator_style: WindowsPathSeparatorStyle,
+        buffer_size: u32,
         meta_tx: Sender<MetaEvent>,
     ) -> Result<ReadDirectoryChangesWatcher> {
         let (cmd_tx, cmd_rx) = unbounded();
@@ -718,6 +767,7 @@ impl ReadDirectoryChangesWatcher {
         let action_tx = ReadDirectoryChangesServer::start(
             event_handler,
             event_kinds,
+            buffer_size,
             meta_tx,
             cmd_tx,
             wakeup_sem,
@@ -796,6 +846,9 @@ impl ReadDirectoryChangesWatcher {
 
 impl Watcher for ReadDirectoryChangesWatcher {
     fn new<F: EventHandler>(event_handler: F, config: Config) -> Result<Self> {
+        if config.windows_buffer_size() < MIN_BUF_SIZE {
+            return Err(Error::invalid_config(&config));
+        }
         // create dummy channel for meta event
         // TODO: determine the original purpose of this - can we remove it?
         let (meta_tx, _) = unbounded();
@@ -804,6 +857,7 @@ impl Watcher for ReadDirectoryChangesWatcher {
             event_handler,
             config.event_kinds(),
             config.windows_path_separator_style(),
+            config.windows_buffer_size(),
             meta_tx,
         )
     }
@@ -978,7 +1032,7 @@ pub mod tests {
         let request = Box::new(super::ReadDirectoryRequest {
             event_handler,
             event_kinds: crate::EventKindMask::ALL,
-            buffer: [0u8; super::BUF_SIZE as usize],
+            buffer: vec![0u32; crate::Config::default().windows_buffer_size() as usize / 4],
             handle: INVALID_HANDLE_VALUE,
             data: super::ReadData {
                 watch_path: invalid_path.clone(),
@@ -1022,7 +1076,7 @@ pub mod tests {
         let request = super::ReadDirectoryRequest {
             event_handler,
             event_kinds: crate::EventKindMask::ALL,
-            buffer: [0u8; super::BUF_SIZE as usize],
+            buffer: vec![0u32; crate::Config::default().windows_buffer_size() as usize / 4],
             handle: INVALID_HANDLE_VALUE,
             data: super::ReadData {
                 watch_path: watch_path.clone(),
@@ -1044,6 +1098,41 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn buffer_size_below_minimum_is_rejected() {
+        let config = crate::Config::default().with_windows_buffer_size(super::MIN_BUF_SIZE - 4);
+        let result = ReadDirectoryChangesWatcher::new(|_: crate::Result<crate::Event>| {}, config);
+
+        assert!(matches!(
+            result.map(|_| ()).map_err(|err| err.kind),
+            Err(crate::ErrorKind::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn buffer_overflow_emits_rescan() {
+        let tmpdir = testdir();
+        let (mut watcher, mut rx) = channel_with_config::<ReadDirectoryChangesWatcher>(
+            ChannelConfig::default().with_watcher_config(
+                crate::Config::default().with_windows_buffer_size(super::MIN_BUF_SIZE),
+            ),
+        );
+        watcher.watch_recursively(&tmpdir);
+
+        // the minimum buffer holds a single record for these names, so a burst overflows it
+        for i in 0..100 {
+            let name = format!("{i:0>200}");
+            std::fs::File::create_new(tmpdir.path().join(name)).expect("create");
+        }
+
+        // waits up to the receiver timeout for each event, stopping once the channel is quiet
+        let rescan = std::iter::from_fn(|| rx.try_recv().ok())
+            .filter_map(|res| res.ok())
+            .find(|event| event.need_rescan())
+            .expect("the overflow wasn't reported");
+        assert_eq!(rescan.paths, [tmpdir.path()]);
+    }
+
     #[test]
     fn auto_separator_style_keeps_relative_slash_watch_style(
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
@@ -1116,6 +1205,51 @@ pub mod tests {
         assert_eq!(normalized_event_path, watched_file);
     }
 
+    #[test]
+    fn event_path_roundtrips_non_bmp_and_unpaired_surrogates() {
+        use std::os::windows::ffi::OsStrExt;
+        use windows_sys::Win32::Foundation::INVALID_HANDLE_VALUE;
+
+        let dir = PathBuf::from(r"C:\watched");
+        let data = super::ReadData {
+            watch_path: dir.clone(),
+            dir: dir.clone(),
+            reported_dir: dir.clone(),
+            file: None,
+            complete_sem: INVALID_HANDLE_VALUE,
+            is_recursive: true,
+            separator_style: SeparatorStyle::Backslash,
+        };
+        // "\📁\😀" followed by a lone high surrogate, which is valid in an NTFS name
+        let mut raw_event_name: Vec<u16> = "\\📁\\😀".encode_utf16().collect();
+        raw_event_name.push(0xD800);
+
+        let (absolute_path, path) = data.event_paths(&raw_event_name);
+
+        let mut expected_wide: Vec<u16> = dir.as_os_str().encode_wide().collect();
+        expected_wide.extend_from_slice(&raw_event_name);
+        for path in [absolute_path, path] {
+            assert_eq!(
+                path.as_os_str().encode_wide().collect::<Vec<_>>(),
+                expected_wide
+            );
+            assert!(path.to_str().is_none());
+        }
+    }
+
+    #[test]
+    fn create_file_with_non_bmp_name() {
+        let tmpdir = testdir();
+        let (mut watcher, mut rx) = watcher();
+        watcher.watch_recursively(&tmpdir);
+
+        let path = tmpdir.path().join("😀 𝄞 entry");
+        std::fs::File::create_new(&path).expect("create");
+
+        rx.wait_ordered_exact([expected(&path).create_file()])
+            .ensure_no_tail();
+    }
+
     #[test]
     fn create_file() {
         let tmpdir = testdir();